@@ -1,4 +1,7 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+mod parser;
 
 trait State: std::hash::Hash + Eq + std::fmt::Debug + Clone {}
 impl<T: std::hash::Hash + Eq + std::fmt::Debug + Clone> State for T {}
@@ -6,14 +9,48 @@ impl<T: std::hash::Hash + Eq + std::fmt::Debug + Clone> State for T {}
 trait Symbol: std::hash::Hash + Eq + std::fmt::Debug + Clone {}
 impl<T: std::hash::Hash + Eq + std::fmt::Debug + Clone> Symbol for T {}
 
-type Transition<M, T> = Box<dyn Fn(M, T) -> (M, Movement, T)>;
+/// A declarative transition table. Reading `symbol` while in `state` may
+/// yield any number of `(next_state, Movement, write_symbol)` rules: zero
+/// successors means "stuck", one means deterministic, and more than one
+/// means the machine is nondeterministic (see `accepts()`).
+pub(crate) type TransitionTable<M, T> = HashMap<(T, M), Vec<(T, Movement, M)>>;
 
-#[derive(Debug)]
-enum Movement {
+/// A single state/tape configuration reached while searching a
+/// nondeterministic machine's configuration space.
+type Configuration<M, T> = (T, VecDeque<M>, usize);
+
+enum Transition<M, T> {
+    /// The original escape hatch: an opaque closure. Cannot be inspected,
+    /// validated, or serialized.
+    Closure(Box<dyn Fn(M, T) -> (M, Movement, T)>),
+    /// A declarative table, validated against `state_set`/`symbol_set` at
+    /// construction time.
+    Table(TransitionTable<M, T>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Movement {
     Left,
     Right,
 }
 
+/// A transition table rule that references a state or symbol outside of
+/// `state_set`/`symbol_set`.
+#[derive(Debug)]
+struct TransitionTableError(Vec<String>);
+
+impl fmt::Display for TransitionTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "invalid transition table:")?;
+        for problem in &self.0 {
+            writeln!(f, "  - {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for TransitionTableError {}
+
 /// Hopcroft & Ullman's Turing machine
 struct TuringMachine<M: Symbol, T: State> {
     // Q
@@ -37,6 +74,32 @@ struct TuringMachine<M: Symbol, T: State> {
     current_state: T,
 }
 
+/// A record of one applied transition, as yielded by `TuringMachine::trace`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step<M, T> {
+    pre_state: T,
+    read_symbol: M,
+    written_symbol: M,
+    movement: Movement,
+    post_state: T,
+    /// Head position after the move.
+    head: usize,
+    /// Tape snapshot after the move.
+    tape: VecDeque<M>,
+}
+
+/// The result of attempting a single transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepOutcome {
+    /// A rule applied and the machine advanced.
+    Applied,
+    /// `current_state` is already in `final_state_set`.
+    Halted,
+    /// `current_state` is not final, but no rule covers the symbol under the
+    /// head.
+    Stuck,
+}
+
 impl<M: Symbol, T: State> TuringMachine<M, T> {
     fn new(
         state_set: HashSet<T>,
@@ -44,7 +107,7 @@ impl<M: Symbol, T: State> TuringMachine<M, T> {
         blank_symbol: M,
         initial_state: T,
         final_state_set: HashSet<T>,
-        transition: Transition<M, T>,
+        transition: Box<dyn Fn(M, T) -> (M, Movement, T)>,
         tape: VecDeque<M>,
     ) -> Self {
         assert!(!state_set.is_empty());
@@ -53,6 +116,83 @@ impl<M: Symbol, T: State> TuringMachine<M, T> {
         assert!(!symbol_set.is_empty());
         assert!(symbol_set.contains(&blank_symbol));
 
+        Self::from_transition(
+            state_set,
+            symbol_set,
+            blank_symbol,
+            initial_state,
+            final_state_set,
+            Transition::Closure(transition),
+            tape,
+        )
+    }
+
+    /// Builds a machine from a declarative transition table instead of an
+    /// opaque closure, which makes it possible to inspect, validate, and
+    /// (eventually) serialize the program. Every rule's state and symbols
+    /// must belong to `state_set`/`symbol_set`; otherwise every offending
+    /// rule is reported in the returned error.
+    fn from_table(
+        state_set: HashSet<T>,
+        symbol_set: HashSet<M>,
+        blank_symbol: M,
+        initial_state: T,
+        final_state_set: HashSet<T>,
+        table: TransitionTable<M, T>,
+        tape: VecDeque<M>,
+    ) -> Result<Self, TransitionTableError> {
+        assert!(!state_set.is_empty());
+        assert!(state_set.contains(&initial_state));
+
+        assert!(!symbol_set.is_empty());
+        assert!(symbol_set.contains(&blank_symbol));
+
+        let mut problems = Vec::new();
+        for ((state, read_symbol), successors) in &table {
+            if !state_set.contains(state) {
+                problems.push(format!("rule for ({state:?}, {read_symbol:?}) reads undeclared state {state:?}"));
+            }
+            if !symbol_set.contains(read_symbol) {
+                problems.push(format!("rule for ({state:?}, {read_symbol:?}) reads undeclared symbol {read_symbol:?}"));
+            }
+            for (next_state, _movement, write_symbol) in successors {
+                if !state_set.contains(next_state) {
+                    problems.push(format!(
+                        "rule for ({state:?}, {read_symbol:?}) transitions to undeclared state {next_state:?}"
+                    ));
+                }
+                if !symbol_set.contains(write_symbol) {
+                    problems.push(format!(
+                        "rule for ({state:?}, {read_symbol:?}) writes undeclared symbol {write_symbol:?}"
+                    ));
+                }
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(TransitionTableError(problems));
+        }
+
+        Ok(Self::from_transition(
+            state_set,
+            symbol_set,
+            blank_symbol,
+            initial_state,
+            final_state_set,
+            Transition::Table(table),
+            tape,
+        ))
+    }
+
+    fn from_transition(
+        state_set: HashSet<T>,
+        symbol_set: HashSet<M>,
+        blank_symbol: M,
+        initial_state: T,
+        final_state_set: HashSet<T>,
+        transition: Transition<M, T>,
+        tape: VecDeque<M>,
+    ) -> Self {
         let head = Default::default();
         let current_state = initial_state.clone();
 
@@ -69,19 +209,46 @@ impl<M: Symbol, T: State> TuringMachine<M, T> {
         }
     }
 
-    fn step(&mut self) -> bool {
+    fn step(&mut self) -> StepOutcome {
         if self.final_state_set.contains(&self.current_state) {
-            return false;
+            return StepOutcome::Halted;
         }
-        let (symbol, movement, state) =
-            (self.transition)(self.tape[self.head].clone(), self.current_state.clone());
 
-        println!(
-            "{:?} {:?} => {:?} {:?} {:?}",
-            self.current_state, self.tape[self.head], state, symbol, movement
-        );
-        self.current_state = state;
-        self.tape[self.head] = symbol;
+        match self.apply() {
+            Some(_) => StepOutcome::Applied,
+            None => StepOutcome::Stuck,
+        }
+    }
+
+    /// Looks up and applies the rule for the current state/symbol, mutating
+    /// the machine and returning what happened, or `None` if no rule covers
+    /// it. Does not check `final_state_set`; callers that care about halting
+    /// (`step()`, `trace()`) check it themselves first.
+    ///
+    /// Deliberately doesn't snapshot the tape: `step()`/`run()`/
+    /// `run_bounded()` call this every transition and don't need one, and
+    /// cloning it on their behalf would turn a long run into O(steps *
+    /// tape_len) work. `trace()` builds a `Step` (tape snapshot included)
+    /// around this return value instead.
+    fn apply(&mut self) -> Option<(T, M, M, Movement, T)> {
+        let pre_state = self.current_state.clone();
+        let read_symbol = self.tape[self.head].clone();
+
+        let rule = match &self.transition {
+            Transition::Closure(transition) => {
+                Some(transition(read_symbol.clone(), self.current_state.clone()))
+            }
+            Transition::Table(table) => table
+                .get(&(self.current_state.clone(), read_symbol.clone()))
+                .and_then(|successors| successors.first())
+                .cloned()
+                .map(|(state, movement, symbol)| (symbol, movement, state)),
+        };
+
+        let (written_symbol, movement, post_state) = rule?;
+
+        self.current_state = post_state.clone();
+        self.tape[self.head] = written_symbol.clone();
 
         match movement {
             Movement::Left => {
@@ -99,23 +266,331 @@ impl<M: Symbol, T: State> TuringMachine<M, T> {
                 }
             }
         }
-        true
+
+        Some((pre_state, read_symbol, written_symbol, movement, post_state))
+    }
+
+    /// Drives the machine one transition at a time, yielding a `Step` record
+    /// for each one. Stops (the iterator ends) once a final state is reached
+    /// or no rule applies. Callers can collect this to inspect or
+    /// pretty-print a run, or assert on specific steps in tests, instead of
+    /// the machine printing its own transitions.
+    fn trace(&mut self) -> impl Iterator<Item = Step<M, T>> + '_ {
+        std::iter::from_fn(move || {
+            if self.final_state_set.contains(&self.current_state) {
+                return None;
+            }
+            let (pre_state, read_symbol, written_symbol, movement, post_state) = self.apply()?;
+            Some(Step {
+                pre_state,
+                read_symbol,
+                written_symbol,
+                movement,
+                post_state,
+                head: self.head,
+                tape: self.tape.clone(),
+            })
+        })
     }
 
     fn run(&mut self) -> VecDeque<M> {
-        while self.step() {}
-        while !self.final_state_set.contains(&self.current_state) {
-            self.step();
-        }
+        while let StepOutcome::Applied = self.step() {}
         self.tape.clone()
     }
+
+    /// Like `run()`, but safe to call on a machine that may never halt: stops
+    /// after at most `max_steps` transitions and reports why execution
+    /// stopped rather than hanging forever.
+    fn run_bounded(&mut self, max_steps: usize) -> RunOutcome<VecDeque<M>, T> {
+        let mut steps = 0;
+        loop {
+            if steps >= max_steps && !self.final_state_set.contains(&self.current_state) {
+                return RunOutcome::StepLimitExceeded {
+                    tape: self.tape.clone(),
+                    steps,
+                };
+            }
+
+            match self.step() {
+                StepOutcome::Applied => steps += 1,
+                StepOutcome::Halted => {
+                    return RunOutcome::Halted {
+                        tape: self.tape.clone(),
+                        steps,
+                    }
+                }
+                StepOutcome::Stuck => {
+                    return RunOutcome::Stuck {
+                        tape: self.tape.clone(),
+                        steps,
+                        state: self.current_state.clone(),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Performs a breadth-first search over the configuration space of a
+    /// nondeterministic, table-based machine, expanding every applicable
+    /// rule at each step rather than just the first. Returns `true` as soon
+    /// as some reachable configuration's state is final, `false` if the
+    /// frontier empties first, and gives up past `max_depth` either way.
+    ///
+    /// Only supported for `from_table` machines: a closure-based transition
+    /// has no way to enumerate all of its successors, so this returns `Err`
+    /// rather than panicking when called on one.
+    fn accepts(&self, max_depth: usize) -> Result<bool, NotTableBased> {
+        let Transition::Table(table) = &self.transition else {
+            return Err(NotTableBased);
+        };
+
+        let initial: Configuration<M, T> = (self.initial_state.clone(), self.tape.clone(), self.head);
+
+        let mut visited = HashSet::from([initial.clone()]);
+        let mut frontier = VecDeque::from([(initial, 0usize)]);
+
+        while let Some(((state, tape, head), depth)) = frontier.pop_front() {
+            if self.final_state_set.contains(&state) {
+                return Ok(true);
+            }
+
+            if depth >= max_depth {
+                continue;
+            }
+
+            let Some(successors) = table.get(&(state, tape[head].clone())) else {
+                continue;
+            };
+
+            for (next_state, movement, write_symbol) in successors {
+                let mut next_tape = tape.clone();
+                next_tape[head] = write_symbol.clone();
+                let mut next_head = head;
+
+                match movement {
+                    Movement::Left => {
+                        if next_head == 0 {
+                            next_tape.push_front(self.blank_symbol.clone());
+                        } else {
+                            next_head -= 1;
+                        }
+                    }
+                    Movement::Right => {
+                        next_head += 1;
+                        if next_tape.len() <= next_head {
+                            next_tape.push_back(self.blank_symbol.clone());
+                        }
+                    }
+                }
+
+                let next_configuration = (next_state.clone(), next_tape, next_head);
+                if visited.insert(next_configuration.clone()) {
+                    frontier.push_back((next_configuration, depth + 1));
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// `accepts()` was called on a machine built via the closure constructor,
+/// which has no way to enumerate all of its successors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct NotTableBased;
+
+impl fmt::Display for NotTableBased {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "accepts() requires a table-based transition")
+    }
+}
+
+impl std::error::Error for NotTableBased {}
+
+/// The outcome of a bounded run, carrying a tape snapshot in every variant.
+/// Generic over the tape-snapshot type `Tape` so both single-tape
+/// (`VecDeque<M>`) and multi-tape (`[VecDeque<M>; K]`) machines can share it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RunOutcome<Tape, T> {
+    /// A final state was reached.
+    Halted { tape: Tape, steps: usize },
+    /// `max_steps` transitions ran without reaching a final state.
+    StepLimitExceeded { tape: Tape, steps: usize },
+    /// Execution halted early because no rule covered the current
+    /// state/symbol pair.
+    Stuck { tape: Tape, steps: usize, state: T },
+}
+
+/// A declarative transition table for a `K`-tape machine: the state plus the
+/// symbol under each head maps to a next state and a per-tape
+/// (`Movement`, write-symbol) pair.
+type MultiTapeTransitionTable<M, T, const K: usize> = HashMap<(T, [M; K]), (T, [(Movement, M); K])>;
+
+/// A Turing machine with `K` independent tapes, each with its own head.
+/// Useful for expressing algorithms like copying or binary addition, which
+/// are awkward to express on a single tape.
+struct MultiTapeTuringMachine<M: Symbol, T: State, const K: usize> {
+    // Q
+    state_set: HashSet<T>,
+    // Г
+    symbol_set: HashSet<M>,
+    // b
+    blank_symbol: M,
+    // δ
+    transition: MultiTapeTransitionTable<M, T, K>,
+    // q0
+    initial_state: T,
+    // F
+    final_state_set: HashSet<T>,
+
+    // implementation specific fields
+    heads: [usize; K],
+    tapes: [VecDeque<M>; K],
+    current_state: T,
+}
+
+impl<M: Symbol, T: State, const K: usize> MultiTapeTuringMachine<M, T, K> {
+    /// Builds a multi-tape machine from a declarative transition table,
+    /// validating every rule's state and symbols against
+    /// `state_set`/`symbol_set` the same way `TuringMachine::from_table`
+    /// does.
+    fn from_table(
+        state_set: HashSet<T>,
+        symbol_set: HashSet<M>,
+        blank_symbol: M,
+        initial_state: T,
+        final_state_set: HashSet<T>,
+        transition: MultiTapeTransitionTable<M, T, K>,
+        tapes: [VecDeque<M>; K],
+    ) -> Result<Self, TransitionTableError> {
+        assert!(!state_set.is_empty());
+        assert!(state_set.contains(&initial_state));
+
+        assert!(!symbol_set.is_empty());
+        assert!(symbol_set.contains(&blank_symbol));
+
+        let mut problems = Vec::new();
+        for ((state, read_symbols), (next_state, writes)) in &transition {
+            if !state_set.contains(state) {
+                problems.push(format!("rule for ({state:?}, {read_symbols:?}) reads undeclared state {state:?}"));
+            }
+            for read_symbol in read_symbols {
+                if !symbol_set.contains(read_symbol) {
+                    problems.push(format!(
+                        "rule for ({state:?}, {read_symbols:?}) reads undeclared symbol {read_symbol:?}"
+                    ));
+                }
+            }
+            if !state_set.contains(next_state) {
+                problems.push(format!(
+                    "rule for ({state:?}, {read_symbols:?}) transitions to undeclared state {next_state:?}"
+                ));
+            }
+            for (_movement, write_symbol) in writes {
+                if !symbol_set.contains(write_symbol) {
+                    problems.push(format!(
+                        "rule for ({state:?}, {read_symbols:?}) writes undeclared symbol {write_symbol:?}"
+                    ));
+                }
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(TransitionTableError(problems));
+        }
+
+        let heads = [0; K];
+        let current_state = initial_state.clone();
+
+        Ok(Self {
+            state_set,
+            symbol_set,
+            blank_symbol,
+            initial_state,
+            final_state_set,
+            transition,
+            heads,
+            tapes,
+            current_state,
+        })
+    }
+
+    fn step(&mut self) -> StepOutcome {
+        if self.final_state_set.contains(&self.current_state) {
+            return StepOutcome::Halted;
+        }
+
+        let read_symbols: [M; K] = std::array::from_fn(|i| self.tapes[i][self.heads[i]].clone());
+
+        let Some((next_state, writes)) = self
+            .transition
+            .get(&(self.current_state.clone(), read_symbols))
+            .cloned()
+        else {
+            return StepOutcome::Stuck;
+        };
+
+        self.current_state = next_state;
+
+        for (i, (movement, write_symbol)) in writes.into_iter().enumerate() {
+            self.tapes[i][self.heads[i]] = write_symbol;
+
+            match movement {
+                Movement::Left => {
+                    if self.heads[i] == 0 {
+                        self.tapes[i].push_front(self.blank_symbol.clone());
+                    } else {
+                        self.heads[i] -= 1;
+                    }
+                }
+                Movement::Right => {
+                    self.heads[i] += 1;
+
+                    if self.tapes[i].len() <= self.heads[i] {
+                        self.tapes[i].push_back(self.blank_symbol.clone());
+                    }
+                }
+            }
+        }
+
+        StepOutcome::Applied
+    }
+
+    fn run_bounded(&mut self, max_steps: usize) -> RunOutcome<[VecDeque<M>; K], T> {
+        let mut steps = 0;
+        loop {
+            if steps >= max_steps && !self.final_state_set.contains(&self.current_state) {
+                return RunOutcome::StepLimitExceeded {
+                    tape: self.tapes.clone(),
+                    steps,
+                };
+            }
+
+            match self.step() {
+                StepOutcome::Applied => steps += 1,
+                StepOutcome::Halted => {
+                    return RunOutcome::Halted {
+                        tape: self.tapes.clone(),
+                        steps,
+                    }
+                }
+                StepOutcome::Stuck => {
+                    return RunOutcome::Stuck {
+                        tape: self.tapes.clone(),
+                        steps,
+                        state: self.current_state.clone(),
+                    }
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::collections::{HashSet, VecDeque};
 
-    use crate::{Movement, TuringMachine};
+    use crate::{Movement, MultiTapeTuringMachine, RunOutcome, Step, TuringMachine};
 
     #[test]
     fn busy_beaver() {
@@ -142,4 +617,221 @@ mod test {
         let result = turing_machine.run();
         println!("RESULT: {:?}", result);
     }
+
+    #[test]
+    fn from_table_rejects_undeclared_state() {
+        let mut table = std::collections::HashMap::new();
+        table.insert(('A', '0'), vec![('Z', Movement::Right, '1')]);
+
+        let result = TuringMachine::from_table(
+            HashSet::from(['A', 'H']),
+            HashSet::from(['0', '1']),
+            '0',
+            'A',
+            HashSet::from(['H']),
+            table,
+            VecDeque::from(['0']),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn from_table_runs_busy_beaver() {
+        let table = HashMapBuilder::busy_beaver();
+
+        let mut turing_machine = TuringMachine::from_table(
+            HashSet::from(['A', 'B', 'C', 'H']),
+            HashSet::from(['0', '1']),
+            '0',
+            'A',
+            HashSet::from(['H']),
+            table,
+            VecDeque::from(['0']),
+        )
+        .unwrap();
+
+        let result = turing_machine.run();
+        assert_eq!(result, VecDeque::from(['1', '1', '1', '1', '1', '1']));
+    }
+
+    #[test]
+    fn trace_yields_a_step_per_transition_and_stops_at_halt() {
+        let table = HashMapBuilder::busy_beaver();
+
+        let mut turing_machine = TuringMachine::from_table(
+            HashSet::from(['A', 'B', 'C', 'H']),
+            HashSet::from(['0', '1']),
+            '0',
+            'A',
+            HashSet::from(['H']),
+            table,
+            VecDeque::from(['0']),
+        )
+        .unwrap();
+
+        let steps: Vec<Step<char, char>> = turing_machine.trace().collect();
+
+        assert_eq!(steps.len(), 13);
+        assert_eq!(steps[0].pre_state, 'A');
+        assert_eq!(steps[0].read_symbol, '0');
+        assert_eq!(steps[0].written_symbol, '1');
+        assert_eq!(steps[0].movement, Movement::Right);
+        assert_eq!(steps[0].post_state, 'B');
+        assert_eq!(steps.last().unwrap().post_state, 'H');
+    }
+
+    #[test]
+    fn run_bounded_reports_step_limit_exceeded() {
+        let table = HashMapBuilder::busy_beaver();
+
+        let mut turing_machine = TuringMachine::from_table(
+            HashSet::from(['A', 'B', 'C', 'H']),
+            HashSet::from(['0', '1']),
+            '0',
+            'A',
+            HashSet::from(['H']),
+            table,
+            VecDeque::from(['0']),
+        )
+        .unwrap();
+
+        let outcome = turing_machine.run_bounded(2);
+        assert!(matches!(outcome, RunOutcome::StepLimitExceeded { steps: 2, .. }));
+    }
+
+    #[test]
+    fn run_bounded_reports_stuck() {
+        let table = std::collections::HashMap::from([(('A', '0'), vec![('H', Movement::Right, '1')])]);
+
+        let mut turing_machine = TuringMachine::from_table(
+            HashSet::from(['A', 'H']),
+            HashSet::from(['0', '1']),
+            '0',
+            'A',
+            HashSet::from([]),
+            table,
+            VecDeque::from(['0']),
+        )
+        .unwrap();
+
+        let outcome = turing_machine.run_bounded(10);
+        assert!(matches!(outcome, RunOutcome::Stuck { steps: 1, state: 'H', .. }));
+    }
+
+    #[test]
+    fn accepts_finds_reachable_final_state_via_nondeterministic_choice() {
+        let table = std::collections::HashMap::from([(
+            ('A', '0'),
+            vec![
+                ('A', Movement::Right, '0'),
+                ('H', Movement::Right, '1'),
+            ],
+        )]);
+
+        let turing_machine = TuringMachine::from_table(
+            HashSet::from(['A', 'H']),
+            HashSet::from(['0', '1']),
+            '0',
+            'A',
+            HashSet::from(['H']),
+            table,
+            VecDeque::from(['0']),
+        )
+        .unwrap();
+
+        assert!(turing_machine.accepts(5).unwrap());
+    }
+
+    #[test]
+    fn accepts_gives_up_past_max_depth() {
+        let table = std::collections::HashMap::from([(
+            ('A', '0'),
+            vec![('A', Movement::Right, '0')],
+        )]);
+
+        let turing_machine = TuringMachine::from_table(
+            HashSet::from(['A', 'H']),
+            HashSet::from(['0', '1']),
+            '0',
+            'A',
+            HashSet::from(['H']),
+            table,
+            VecDeque::from(['0']),
+        )
+        .unwrap();
+
+        assert!(!turing_machine.accepts(3).unwrap());
+    }
+
+    #[test]
+    fn accepts_rejects_closure_based_machines() {
+        let transition = |symbol: char, current_state: char| match (symbol, current_state) {
+            ('0', 'A') => ('1', Movement::Right, 'H'),
+            _ => unreachable!(),
+        };
+
+        let turing_machine = TuringMachine::new(
+            HashSet::from(['A', 'H']),
+            HashSet::from(['0', '1']),
+            '0',
+            'A',
+            HashSet::from(['H']),
+            Box::new(transition),
+            VecDeque::from(['0']),
+        );
+
+        assert!(turing_machine.accepts(5).is_err());
+    }
+
+    #[test]
+    fn multi_tape_copies_input_onto_second_tape() {
+        let table = std::collections::HashMap::from([
+            (
+                ("Copy", ['1', '_']),
+                ("Copy", [(Movement::Right, '1'), (Movement::Right, '1')]),
+            ),
+            (
+                ("Copy", ['_', '_']),
+                ("H", [(Movement::Right, '_'), (Movement::Right, '_')]),
+            ),
+        ]);
+
+        let mut turing_machine = MultiTapeTuringMachine::from_table(
+            HashSet::from(["Copy", "H"]),
+            HashSet::from(['1', '_']),
+            '_',
+            "Copy",
+            HashSet::from(["H"]),
+            table,
+            [
+                VecDeque::from(['1', '1', '1']),
+                VecDeque::from(['_']),
+            ],
+        )
+        .unwrap();
+
+        let outcome = turing_machine.run_bounded(10);
+        let RunOutcome::Halted { tape, .. } = outcome else {
+            panic!("expected the machine to halt, got {outcome:?}");
+        };
+        assert_eq!(tape[1], VecDeque::from(['1', '1', '1', '_', '_']));
+    }
+
+    type BusyBeaverTable = std::collections::HashMap<(char, char), Vec<(char, Movement, char)>>;
+
+    struct HashMapBuilder;
+
+    impl HashMapBuilder {
+        fn busy_beaver() -> BusyBeaverTable {
+            std::collections::HashMap::from([
+                (('A', '0'), vec![('B', Movement::Right, '1')]),
+                (('B', '0'), vec![('A', Movement::Left, '1')]),
+                (('C', '0'), vec![('B', Movement::Left, '1')]),
+                (('A', '1'), vec![('C', Movement::Left, '1')]),
+                (('B', '1'), vec![('B', Movement::Right, '1')]),
+                (('C', '1'), vec![('H', Movement::Right, '1')]),
+            ])
+        }
+    }
 }
@@ -0,0 +1,298 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::{Movement, TransitionTable};
+
+/// A Turing-machine program parsed from the conventional quintuple text
+/// format: a header declaring the blank symbol, initial state, and final
+/// states, followed by one rule per line.
+///
+/// `state_set`/`symbol_set` are inferred from every state and symbol
+/// mentioned anywhere in the program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ProgramTable {
+    pub(crate) state_set: HashSet<String>,
+    pub(crate) symbol_set: HashSet<String>,
+    pub(crate) blank_symbol: String,
+    pub(crate) initial_state: String,
+    pub(crate) final_state_set: HashSet<String>,
+    pub(crate) table: TransitionTable<String, String>,
+}
+
+/// A malformed `.tm` program, with the 1-based line/column of the offending
+/// text. Header errors (a missing `blank:`/`initial:` declaration) report
+/// line/column `0`, since there is no single offending line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+    pub(crate) message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find('#') {
+        Some(index) => &line[..index],
+        None => line,
+    }
+}
+
+/// 1-based column of the `token_index`-th whitespace-separated token of
+/// `line`.
+fn column_of(line: &str, token_index: usize) -> usize {
+    line.char_indices()
+        .filter(|&(index, c)| {
+            !c.is_whitespace() && line[..index].chars().next_back().map_or(true, char::is_whitespace)
+        })
+        .nth(token_index)
+        .map_or(1, |(index, _)| index + 1)
+}
+
+/// Parses the conventional quintuple notation:
+///
+/// ```text
+/// blank: _
+/// initial: A
+/// final: H
+///
+/// A 0 -> 1 R B   # comments run to end of line
+/// B 0 -> 1 L A
+/// ```
+///
+/// Each rule line is `current_state read_symbol -> write_symbol {L|R}
+/// next_state`. Blank lines and `#` comments are ignored. `final:` may
+/// appear more than once to declare multiple final states.
+pub(crate) fn parse_program(input: &str) -> Result<ProgramTable, ParseError> {
+    let mut state_set = HashSet::new();
+    let mut symbol_set = HashSet::new();
+    let mut blank_symbol = None;
+    let mut initial_state = None;
+    let mut final_state_set = HashSet::new();
+    let mut table: TransitionTable<String, String> = HashMap::new();
+
+    for (line_number, raw_line) in input.lines().enumerate() {
+        let line_number = line_number + 1;
+        let line = strip_comment(raw_line).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(value) = line.strip_prefix("blank:") {
+            blank_symbol = Some(value.trim().to_string());
+            continue;
+        }
+        if let Some(value) = line.strip_prefix("initial:") {
+            initial_state = Some(value.trim().to_string());
+            continue;
+        }
+        if let Some(value) = line.strip_prefix("final:") {
+            let state = value.trim().to_string();
+            final_state_set.insert(state.clone());
+            state_set.insert(state);
+            continue;
+        }
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let [state, read_symbol, arrow, write_symbol, movement, next_state] = tokens[..] else {
+            return Err(ParseError {
+                line: line_number,
+                column: 1,
+                message: format!(
+                    "expected `current_state read_symbol -> write_symbol {{L|R}} next_state`, found `{line}`"
+                ),
+            });
+        };
+
+        if arrow != "->" {
+            return Err(ParseError {
+                line: line_number,
+                column: column_of(raw_line, 2),
+                message: format!("expected `->`, found `{arrow}`"),
+            });
+        }
+
+        let movement = match movement {
+            "L" => Movement::Left,
+            "R" => Movement::Right,
+            other => {
+                return Err(ParseError {
+                    line: line_number,
+                    column: column_of(raw_line, 4),
+                    message: format!("expected `L` or `R`, found `{other}`"),
+                })
+            }
+        };
+
+        state_set.insert(state.to_string());
+        state_set.insert(next_state.to_string());
+        symbol_set.insert(read_symbol.to_string());
+        symbol_set.insert(write_symbol.to_string());
+
+        table
+            .entry((state.to_string(), read_symbol.to_string()))
+            .or_default()
+            .push((next_state.to_string(), movement, write_symbol.to_string()));
+    }
+
+    let blank_symbol = blank_symbol.ok_or_else(|| ParseError {
+        line: 0,
+        column: 0,
+        message: "missing `blank:` header declaring the blank symbol".to_string(),
+    })?;
+    let initial_state = initial_state.ok_or_else(|| ParseError {
+        line: 0,
+        column: 0,
+        message: "missing `initial:` header declaring the initial state".to_string(),
+    })?;
+
+    symbol_set.insert(blank_symbol.clone());
+    state_set.insert(initial_state.clone());
+
+    Ok(ProgramTable {
+        state_set,
+        symbol_set,
+        blank_symbol,
+        initial_state,
+        final_state_set,
+        table,
+    })
+}
+
+impl ProgramTable {
+    /// Serializes back to the text format `parse_program` accepts. Rules are
+    /// emitted in sorted order so round-tripping a program is deterministic
+    /// despite the underlying table being a `HashMap`.
+    pub(crate) fn to_program_string(&self) -> String {
+        let mut output = format!("blank: {}\ninitial: {}\n", self.blank_symbol, self.initial_state);
+
+        let mut final_states: Vec<&String> = self.final_state_set.iter().collect();
+        final_states.sort();
+        for state in final_states {
+            output.push_str(&format!("final: {state}\n"));
+        }
+        output.push('\n');
+
+        let mut rules: Vec<String> = self
+            .table
+            .iter()
+            .flat_map(|((state, read_symbol), successors)| {
+                successors.iter().map(move |(next_state, movement, write_symbol)| {
+                    let movement = match movement {
+                        Movement::Left => "L",
+                        Movement::Right => "R",
+                    };
+                    format!("{state} {read_symbol} -> {write_symbol} {movement} {next_state}")
+                })
+            })
+            .collect();
+        rules.sort();
+
+        for rule in rules {
+            output.push_str(&rule);
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_busy_beaver_program() {
+        let program = parse_program(
+            "blank: 0\n\
+             initial: A\n\
+             final: H\n\
+             \n\
+             # busy beaver\n\
+             A 0 -> 1 R B\n\
+             B 0 -> 1 L A\n\
+             C 0 -> 1 L B\n\
+             A 1 -> 1 L C\n\
+             B 1 -> 1 R B\n\
+             C 1 -> 1 R H\n",
+        )
+        .unwrap();
+
+        assert_eq!(program.blank_symbol, "0");
+        assert_eq!(program.initial_state, "A");
+        assert_eq!(program.final_state_set, HashSet::from(["H".to_string()]));
+        assert_eq!(
+            program.table[&("A".to_string(), "0".to_string())],
+            vec![("B".to_string(), Movement::Right, "1".to_string())]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_movement() {
+        let error = parse_program("blank: 0\ninitial: A\nfinal: H\nA 0 -> 1 X B\n").unwrap_err();
+        assert_eq!(error.line, 4);
+        assert_eq!(error.message, "expected `L` or `R`, found `X`");
+    }
+
+    #[test]
+    fn rejects_missing_blank_header() {
+        let error = parse_program("initial: A\nfinal: H\nA 0 -> 1 R B\n").unwrap_err();
+        assert_eq!(error.line, 0);
+    }
+
+    #[test]
+    fn round_trips_through_to_program_string() {
+        let program = parse_program("blank: 0\ninitial: A\nfinal: H\nA 0 -> 1 R B\nB 0 -> 1 L H\n").unwrap();
+        let serialized = program.to_program_string();
+        let reparsed = parse_program(&serialized).unwrap();
+        assert_eq!(program, reparsed);
+    }
+
+    #[test]
+    fn parsed_busy_beaver_program_runs_via_from_table() {
+        use std::collections::VecDeque;
+
+        use crate::{RunOutcome, TuringMachine};
+
+        let program = parse_program(
+            "blank: 0\n\
+             initial: A\n\
+             final: H\n\
+             \n\
+             A 0 -> 1 R B\n\
+             B 0 -> 1 L A\n\
+             C 0 -> 1 L B\n\
+             A 1 -> 1 L C\n\
+             B 1 -> 1 R B\n\
+             C 1 -> 1 R H\n",
+        )
+        .unwrap();
+
+        let mut turing_machine = TuringMachine::from_table(
+            program.state_set,
+            program.symbol_set,
+            program.blank_symbol.clone(),
+            program.initial_state,
+            program.final_state_set,
+            program.table,
+            VecDeque::from([program.blank_symbol]),
+        )
+        .unwrap();
+
+        let outcome = turing_machine.run_bounded(20);
+        let RunOutcome::Halted { tape, steps } = outcome else {
+            panic!("expected the machine to halt, got {outcome:?}");
+        };
+        assert_eq!(steps, 13);
+        assert_eq!(
+            tape,
+            VecDeque::from(["1", "1", "1", "1", "1", "1"].map(str::to_string))
+        );
+    }
+}